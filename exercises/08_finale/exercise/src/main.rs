@@ -13,6 +13,19 @@ enum MatcherToken<'s> {
     /// This is when you're happy to accept any single character.
     /// It looks like `.`
     WildCard,
+    /// This is when the preceding token can repeat. It looks like `x*` (`min` 0, `max`
+    /// unbounded), `x+` (`min` 1, `max` unbounded) or `x?` (`min` 0, `max` 1).
+    Repeat {
+        inner: Box<MatcherToken<'s>>,
+        min: usize,
+        max: Option<usize>,
+    },
+    /// One of several token sub-sequences, each of which must match *in full* for that
+    /// alternative to be picked. Unlike `OneOfText` (a flat choice of literal strings), each
+    /// alternative here can itself be a factored sequence -- this is what lets
+    /// `MatcherBuilder::compile` keep factoring shared structure among a *subset* of words
+    /// that don't all share a single global prefix/suffix.
+    OneOfSequences(Vec<Vec<MatcherToken<'s>>>),
 }
 
 impl<'s> MatcherToken<'s> {
@@ -22,25 +35,180 @@ impl<'s> MatcherToken<'s> {
                     Option::Some(&input[..text_to_match.len()]),
 
             MatcherToken::OneOfText(ref choices) => {
-                let mut longest_match = 0;
+                // `Option<usize>` (rather than a `0`-initialized `usize`) so that a choice
+                // which is itself the empty string -- as `MatcherBuilder` produces for a word
+                // that's exactly the shared prefix+suffix with nothing in between -- can still
+                // be selected as a (zero-width) match instead of always losing to "no match".
+                let mut longest_match: Option<usize> = Option::None;
                 for &ch in choices.iter() {
-                    if input.starts_with(ch) && ch.len() > longest_match {
-                        longest_match = ch.len();
+                    if input.starts_with(ch) && longest_match.is_none_or(|cur| ch.len() > cur) {
+                        longest_match = Option::Some(ch.len());
                     }
                 }
-                if longest_match > 0 {
-                    Option::Some(&input[..longest_match])
-                } else {
-                    Option::None
+                longest_match.map(|len| &input[..len])
+            },
+            MatcherToken::OneOfSequences(ref sequences) => {
+                let mut longest_match: Option<usize> = Option::None;
+                for seq in sequences.iter() {
+                    if let Option::Some(matched) = match_token_sequence(seq, input) {
+                        if longest_match.is_none_or(|cur| matched.len() > cur) {
+                            longest_match = Option::Some(matched.len());
+                        }
+                    }
                 }
+                longest_match.map(|len| &input[..len])
             },
             MatcherToken::WildCard if !input.is_empty() => {
                 let first_char = input.chars().next().unwrap();
                 Option::Some(&input[..first_char.len_utf8()])
             },
+            MatcherToken::Repeat { ref inner, min, max } =>
+                match_repeated(input, |remaining| inner.match_string(remaining), *min, *max),
             _ => Option::None,
         }
     }
+
+    /// Like `match_string`, but tolerates up to `max_edits` insertions, deletions or
+    /// substitutions between the matched prefix of `input` and the token's pattern text.
+    /// `WildCard` has no pattern text to fuzz against, and repeating a fuzzy match makes the
+    /// edit budget ambiguous (per repetition, or over the whole run?), so both just fall back
+    /// to the exact match.
+    fn match_string_fuzzy<'x>(&self, input: &'x str, max_edits: u8) -> Option<&'x str> {
+        match self {
+            MatcherToken::RawText(ref text_to_match) =>
+                fuzzy_prefix_match(text_to_match, input, max_edits),
+
+            MatcherToken::OneOfText(ref choices) => {
+                let mut longest_match: Option<&'x str> = Option::None;
+                for &ch in choices.iter() {
+                    if let Option::Some(matched) = fuzzy_prefix_match(ch, input, max_edits) {
+                        if longest_match.is_none_or(|cur| matched.len() > cur.len()) {
+                            longest_match = Option::Some(matched);
+                        }
+                    }
+                }
+                longest_match
+            },
+            MatcherToken::OneOfSequences(ref sequences) => {
+                let mut longest_match: Option<&'x str> = Option::None;
+                for seq in sequences.iter() {
+                    if let Option::Some(matched) = match_token_sequence_fuzzy(seq, input, max_edits) {
+                        if longest_match.is_none_or(|cur| matched.len() > cur.len()) {
+                            longest_match = Option::Some(matched);
+                        }
+                    }
+                }
+                longest_match
+            },
+            MatcherToken::WildCard | MatcherToken::Repeat { .. } => self.match_string(input),
+        }
+    }
+}
+
+/// Matches every token in `tokens` in turn against successive slices of `input`, as
+/// `Matcher::match_string` does for a whole pattern -- used by `MatcherToken::OneOfSequences`
+/// to treat a factored sub-sequence as a single alternative that must match in full.
+fn match_token_sequence<'x>(tokens: &[MatcherToken], input: &'x str) -> Option<&'x str> {
+    let mut consumed = 0;
+    for tok in tokens {
+        match tok.match_string(&input[consumed..]) {
+            Option::Some(matched) => consumed += matched.len(),
+            Option::None => return Option::None,
+        }
+    }
+    Option::Some(&input[..consumed])
+}
+
+/// Like `match_token_sequence`, but fuzzy -- each token in the sequence gets its own
+/// `max_edits` budget, same as when matching a flat pattern.
+fn match_token_sequence_fuzzy<'x>(
+    tokens: &[MatcherToken],
+    input: &'x str,
+    max_edits: u8,
+) -> Option<&'x str> {
+    let mut consumed = 0;
+    for tok in tokens {
+        match tok.match_string_fuzzy(&input[consumed..], max_edits) {
+            Option::Some(matched) => consumed += matched.len(),
+            Option::None => return Option::None,
+        }
+    }
+    Option::Some(&input[..consumed])
+}
+
+/// Finds the shortest prefix of `input` whose Levenshtein distance to `pattern` is at most
+/// `max_edits`, returning the matched slice.
+///
+/// This keeps a single DP row `d[0..=pattern.chars().count()]`, where after consuming `i`
+/// characters of `input`, `d[j]` holds the edit distance between the first `i` input
+/// characters and the first `j` pattern characters. The row starts at `d[j] = j` (aligning
+/// zero input characters against `j` pattern characters costs `j` insertions), and each new
+/// input character rolls the row forward with the usual insertion/deletion/substitution
+/// recurrence. As soon as `d[pattern.chars().count()] <= max_edits`, the whole pattern has been matched
+/// within budget, and the already-consumed input is the (shortest) accepting prefix.
+fn fuzzy_prefix_match<'x>(pattern: &str, input: &'x str, max_edits: u8) -> Option<&'x str> {
+    let k = max_edits as usize;
+    let pattern_chars: Vec<char> = pattern.chars().collect();
+    let m = pattern_chars.len();
+    let mut row: Vec<usize> = (0..=m).collect();
+    let mut bytes_consumed = 0;
+
+    // Zero consumed characters is itself a candidate (needed for an empty pattern, which is
+    // already fully "matched" with 0 edits before any input is looked at).
+    if row[m] <= k {
+        return Option::Some(&input[..bytes_consumed]);
+    }
+
+    for c in input.chars() {
+        let mut next_row = vec![0usize; m + 1];
+        next_row[0] = row[0] + 1;
+        for j in 1..=m {
+            let substitution_cost = if pattern_chars[j - 1] == c { 0 } else { 1 };
+            next_row[j] = (row[j] + 1)
+                .min(next_row[j - 1] + 1)
+                .min(row[j - 1] + substitution_cost);
+        }
+        bytes_consumed += c.len_utf8();
+        row = next_row;
+
+        if row[m] <= k {
+            return Option::Some(&input[..bytes_consumed]);
+        }
+        // Band pruning: once every cell in the row is already further from an accepting
+        // state than the budget allows, no later character can bring it back within budget.
+        if *row.iter().min().unwrap() > k {
+            break;
+        }
+    }
+    Option::None
+}
+
+/// Greedily applies `match_one` to `input` as many times in a row as possible (up to `max`
+/// repetitions, if any), stopping at the first repetition that fails to match or that
+/// consumes no input (to avoid looping forever on a zero-width match). Succeeds, consuming
+/// everything matched so far, as long as at least `min` repetitions were found.
+fn match_repeated<'x>(
+    input: &'x str,
+    mut match_one: impl FnMut(&'x str) -> Option<&'x str>,
+    min: usize,
+    max: Option<usize>,
+) -> Option<&'x str> {
+    let mut consumed = 0;
+    let mut count = 0;
+    while max.is_none_or(|max| count < max) {
+        match match_one(&input[consumed..]) {
+            Option::Some(matched) if !matched.is_empty() => {
+                consumed += matched.len();
+                count += 1;
+            }
+            _ => break,
+        }
+    }
+    if count >= min {
+        Option::Some(&input[..consumed])
+    } else {
+        Option::None
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -51,15 +219,61 @@ struct Matcher<'s> {
     tokens: Vec<MatcherToken<'s>>,
     /// This keeps track of the most tokens that this matcher has matched.
     most_tokens_matched: usize,
+    /// The number of insertions/deletions/substitutions `match_string` will tolerate when
+    /// matching a `RawText` or `OneOfText` token against the input. `0` means exact matching.
+    max_edits: u8,
+}
+
+/// A 1-based line/column position within a pattern string, alongside its byte offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    byte: usize,
+    line: usize,
+    col: usize,
+}
+
+/// Tracks the current byte offset and 1-based line/column while scanning a pattern,
+/// so parse errors can point at the character that caused them.
+struct Cursor {
+    byte: usize,
+    line: usize,
+    col: usize,
 }
 
-#[derive(Debug)]
+impl Cursor {
+    fn new() -> Self {
+        Cursor { byte: 0, line: 1, col: 1 }
+    }
+
+    fn position(&self) -> Position {
+        Position { byte: self.byte, line: self.line, col: self.col }
+    }
+
+    fn advance(&mut self, c: char) {
+        self.byte += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.col = 1;
+        } else {
+            self.col += 1;
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 enum MatcherPatternParseError {
-    WildcardInOneOf,
-    RecursiveOneOf,
-    PipeNotAllowedInStandalone,
-    ClosingParenInStandalone,
-    Incomplete,
+    WildcardInOneOf(Position),
+    RecursiveOneOf(Position),
+    PipeNotAllowedInStandalone(Position),
+    ClosingParenInStandalone(Position),
+    Incomplete(Position),
+    /// A `*`, `+` or `?` appeared with no preceding token to bind to, i.e. at the very start
+    /// of a pattern. (Right after `(` it's `QuantifierNotAllowedInOneOf` instead.)
+    QuantifierWithNothingToRepeat(Position),
+    /// Two quantifiers were stacked directly on top of each other, e.g. `a**`.
+    StackedQuantifier(Position),
+    /// A `*`, `+` or `?` appeared inside a `(...)` group, which isn't supported.
+    QuantifierNotAllowedInOneOf(Position),
 }
 
 #[derive(Eq, PartialEq)]
@@ -82,48 +296,68 @@ impl<'p> PatternParser<'p> {
         let mut mat_toks = vec![];
         let mut oneof_choices = vec![];
         let mut parse_mode = ParseMode::Standalone;
-        let mut bytes_so_far = 0;
+        let mut cursor = Cursor::new();
+        // Position of the `(` that opened the group currently being parsed, so an
+        // `Incomplete` error can point at it rather than at the end of the pattern.
+        let mut group_start: Option<Position> = Option::None;
         for c in self.pattern.chars() {
+            let pos = cursor.position();
             if c == '.' {
                 if parse_mode == ParseMode::OneOf {
-                    return Err(MatcherPatternParseError::WildcardInOneOf);
+                    return Err(MatcherPatternParseError::WildcardInOneOf(pos));
                 }
-                self.maybe_extract_token(bytes_so_far)
+                self.maybe_extract_token(cursor.byte)
                     .map(|tok| mat_toks.push(MatcherToken::RawText(tok)));
                 mat_toks.push(MatcherToken::WildCard);
             } else if c == '(' {
                 if parse_mode == ParseMode::OneOf {
-                    return Err(MatcherPatternParseError::RecursiveOneOf);
+                    return Err(MatcherPatternParseError::RecursiveOneOf(pos));
                 }
-                self.maybe_extract_token(bytes_so_far)
+                self.maybe_extract_token(cursor.byte)
                     .map(|tok| mat_toks.push(MatcherToken::RawText(tok)));
                 parse_mode = ParseMode::OneOf;
+                group_start = Option::Some(pos);
             } else if c == '|' {
                 if parse_mode == ParseMode::Standalone {
-                    return Err(MatcherPatternParseError::PipeNotAllowedInStandalone);
+                    return Err(MatcherPatternParseError::PipeNotAllowedInStandalone(pos));
                 }
-                self.maybe_extract_token(bytes_so_far)
+                self.maybe_extract_token(cursor.byte)
                     .map(|tok| oneof_choices.push(tok));
             } else if c == ')' {
                 if parse_mode == ParseMode::Standalone {
-                    return Err(MatcherPatternParseError::ClosingParenInStandalone);
+                    return Err(MatcherPatternParseError::ClosingParenInStandalone(pos));
                 }
-                self.maybe_extract_token(bytes_so_far)
+                self.maybe_extract_token(cursor.byte)
                     .map(|tok| oneof_choices.push(tok));
                 parse_mode = ParseMode::Standalone;
                 mat_toks.push(MatcherToken::OneOfText(oneof_choices.clone()));
                 oneof_choices.clear();
+                group_start = Option::None;
+            } else if c == '*' || c == '+' || c == '?' {
+                if parse_mode == ParseMode::OneOf {
+                    return Err(MatcherPatternParseError::QuantifierNotAllowedInOneOf(pos));
+                }
+                let (min, max) = match c {
+                    '*' => (0, Option::None),
+                    '+' => (1, Option::None),
+                    '?' => (0, Option::Some(1)),
+                    _ => unreachable!(),
+                };
+                let inner = self.take_token_to_repeat(&mut mat_toks, cursor.byte, pos)?;
+                mat_toks.push(MatcherToken::Repeat { inner: Box::new(inner), min, max });
             } else {
                 if self.cur_tok_start.is_none() {
-                    self.cur_tok_start = Option::Some(bytes_so_far);
+                    self.cur_tok_start = Option::Some(cursor.byte);
                 }
             }
-            bytes_so_far += c.len_utf8();
+            cursor.advance(c);
         }
         if parse_mode == ParseMode::OneOf {
-            return Err(MatcherPatternParseError::Incomplete)
+            return Err(MatcherPatternParseError::Incomplete(
+                group_start.expect("OneOf mode is only entered after recording group_start"),
+            ));
         }
-        self.maybe_extract_token(bytes_so_far)
+        self.maybe_extract_token(cursor.byte)
             .map(|tok| mat_toks.push(MatcherToken::RawText(tok)));
         return Ok(mat_toks);
     }
@@ -139,29 +373,70 @@ impl<'p> PatternParser<'p> {
         self.cur_tok_start = Option::None;
         res
     }
+
+    /// Pulls off the token that an immediately-following quantifier should bind to.
+    ///
+    /// If a raw text run is still being accumulated, only its *last character* is bound
+    /// (so `ab*` means "a, then b repeated", not "ab repeated"); the rest is flushed as its
+    /// own `RawText`. Otherwise the quantifier must bind to whatever token was just pushed
+    /// (a `WildCard` or a just-closed `(...)` group) -- it's an error if there isn't one, or
+    /// if it's itself a `Repeat` (stacked quantifiers like `a**` aren't allowed).
+    fn take_token_to_repeat(
+        &mut self,
+        mat_toks: &mut Vec<MatcherToken<'p>>,
+        end_idx: usize,
+        quantifier_pos: Position,
+    ) -> Result<MatcherToken<'p>, MatcherPatternParseError> {
+        if let Option::Some(start_idx) = self.cur_tok_start {
+            let run = &self.pattern[start_idx..end_idx];
+            let last_char = run.chars().next_back()
+                .expect("cur_tok_start is only set once a character has been seen");
+            let prefix = &run[..run.len() - last_char.len_utf8()];
+            if !prefix.is_empty() {
+                mat_toks.push(MatcherToken::RawText(prefix));
+            }
+            self.cur_tok_start = Option::None;
+            return Ok(MatcherToken::RawText(&run[run.len() - last_char.len_utf8()..]));
+        }
+        match mat_toks.pop() {
+            Option::Some(MatcherToken::Repeat { .. }) =>
+                Err(MatcherPatternParseError::StackedQuantifier(quantifier_pos)),
+            Option::Some(tok) => Ok(tok),
+            Option::None => Err(MatcherPatternParseError::QuantifierWithNothingToRepeat(quantifier_pos)),
+        }
+    }
 }
 
 impl<'s> Matcher<'s> {
     /// This should take a string reference, and return
-    /// an `Matcher` which has parsed that reference.
+    /// an `Matcher` which has parsed that reference, or the `Position`-tagged parse error
+    /// that stopped it, so callers can render a caret-underlined diagnostic.
     #[require_lifetimes]
-    fn new(text: &'s str) -> Option<Matcher<'s>> {
+    fn new(text: &'s str) -> Result<Matcher<'s>, MatcherPatternParseError> {
         let pattern = PatternParser::new(&text).parse_into();
         println!("{pattern:?}");
-        pattern.ok().map(|tokens| Matcher {
+        pattern.map(|tokens| Matcher {
             text,
             tokens,
             most_tokens_matched: 0,
+            max_edits: 0,
         })
     }
 
+    /// Sets the number of edits (insertions, deletions, substitutions) that `match_string`
+    /// will tolerate when matching `RawText` and `OneOfText` tokens against the input.
+    fn with_max_edits(mut self, max_edits: u8) -> Self {
+        self.max_edits = max_edits;
+        self
+    }
+
     /// This should take a string, and return a vector of tokens, and the corresponding part
     /// of the given string. For examples, see the test cases below.
     fn match_string<'m>(&mut self, string: &'m str) -> Vec<(&MatcherToken, &'m str)> {
         let mut matched_till = 0;
         let mut match_result = vec![];
         for tok in self.tokens.iter() {
-            if let Some(matched) = tok.match_string(&string[matched_till..]) {
+            if let Some(matched) = tok.match_string_fuzzy(&string[matched_till..], self.max_edits) {
                 matched_till += matched.len();
                 match_result.push((tok, matched))
             } else {
@@ -171,6 +446,194 @@ impl<'s> Matcher<'s> {
         self.most_tokens_matched = max(self.most_tokens_matched, match_result.len());
         match_result
     }
+
+    /// Looks for the pattern anywhere inside `string`, rather than only at byte offset 0.
+    ///
+    /// This tries `match_string` at every `char` boundary of `string` and keeps the best
+    /// candidate, breaking ties in order: the most distinct tokens matched, then the
+    /// smallest byte span between the first and last matched token (the tightest region),
+    /// then the earliest-starting match (since this token sequence always matches its
+    /// tokens contiguously and in pattern order, there are never any gaps to compare).
+    /// `most_tokens_matched` keeps getting updated for every offset that's tried, exactly as
+    /// it would if `match_string` had been called directly at each of those offsets.
+    fn find<'m>(&mut self, string: &'m str) -> Option<(usize, Vec<(&MatcherToken, &'m str)>)> {
+        // Only the score (not the matched tokens themselves) is kept while scanning, so each
+        // offset's `match_string` call can release its borrow of `self` before the next one
+        // starts. Once the winning offset is known, `match_string` is called on it once more
+        // to produce the borrowed result that's actually returned.
+        let mut best: Option<(usize, usize, usize)> = Option::None; // (offset, token_count, span)
+
+        for (offset, _) in string.char_indices() {
+            let candidate = self.match_string(&string[offset..]);
+            if candidate.is_empty() {
+                continue;
+            }
+
+            let count = candidate.len();
+            let span: usize = candidate.iter().map(|(_, matched)| matched.len()).sum();
+            let is_better = match best {
+                Option::None => true,
+                Option::Some((_, best_count, best_span)) => {
+                    if count != best_count {
+                        count > best_count
+                    } else {
+                        span < best_span
+                    }
+                }
+            };
+
+            if is_better {
+                best = Option::Some((offset, count, span));
+            }
+        }
+
+        best.map(|(offset, _, _)| (offset, self.match_string(&string[offset..])))
+    }
+}
+
+/// Compiles a known list of literal alternatives into a `Matcher`, instead of going through
+/// `PatternParser`. Useful when the accepted phrases are known programmatically rather than
+/// as a pattern string -- e.g. a list of month names.
+struct MatcherBuilder;
+
+impl MatcherBuilder {
+    /// Factors `words` into a compact token sequence: the longest shared leading prefix (if
+    /// any) becomes a single `RawText`, the longest shared trailing suffix likewise, and
+    /// whatever's left in the middle becomes one `OneOfText` (or, where some of the middles
+    /// share further structure, a `OneOfSequences` built by `compile_middles`) -- so `match_string` does far
+    /// fewer byte comparisons than it would matching each alternative independently.
+    #[require_lifetimes]
+    // `'w` can't be elided: `#[require_lifetimes]` requires every reference, including the
+    // outer slice, to carry an explicit name.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn from_words<'w, 's>(words: &'w [&'s str]) -> Matcher<'s> {
+        let tokens = Self::compile(words);
+        Matcher {
+            text: words.first().copied().unwrap_or(""),
+            tokens,
+            most_tokens_matched: 0,
+            max_edits: 0,
+        }
+    }
+
+    fn compile<'s>(words: &[&'s str]) -> Vec<MatcherToken<'s>> {
+        if words.is_empty() {
+            return vec![];
+        }
+        if words.len() == 1 {
+            return vec![MatcherToken::RawText(words[0])];
+        }
+
+        let prefix_len = Self::common_prefix_len(words);
+        let suffix_len = Self::common_suffix_len(words, prefix_len);
+
+        let mut tokens = vec![];
+        if prefix_len > 0 {
+            tokens.push(MatcherToken::RawText(&words[0][..prefix_len]));
+        }
+
+        let middles: Vec<&'s str> = words
+            .iter()
+            .map(|word| &word[prefix_len..word.len() - suffix_len])
+            .collect();
+        if let Option::Some(middle_token) = Self::compile_middles(&middles) {
+            tokens.push(middle_token);
+        }
+
+        if suffix_len > 0 {
+            let first = words[0];
+            tokens.push(MatcherToken::RawText(&first[first.len() - suffix_len..]));
+        }
+        tokens
+    }
+
+    /// Turns the "middle" parts left over after stripping a shared prefix/suffix into a
+    /// single token. Rather than always flattening them into one `OneOfText` of literal
+    /// strings, this groups the middles by their leading character and recurses into any
+    /// group with more than one member -- so e.g. "cataclysm"/"catapult"/"category" still
+    /// factor out their shared "cat" even though a fourth word like "dog" stops "cat" from
+    /// being a prefix shared by *every* word. Returns `None` if every middle is empty (the
+    /// prefix+suffix alone already reconstructs every word).
+    ///
+    /// Grouping by leading character is guaranteed to make progress: `common_prefix_len`
+    /// already pulled out everything shared by literally every middle, so they can't all
+    /// share the same next character too -- there are always at least two groups whenever
+    /// there's more than one distinct middle.
+    fn compile_middles<'s>(middles: &[&'s str]) -> Option<MatcherToken<'s>> {
+        if middles.iter().all(|middle| middle.is_empty()) {
+            return Option::None;
+        }
+
+        let mut clusters: Vec<(Option<char>, Vec<&'s str>)> = vec![];
+        for &middle in middles {
+            let key = middle.chars().next();
+            match clusters.iter_mut().find(|(cluster_key, _)| *cluster_key == key) {
+                Option::Some((_, group)) => group.push(middle),
+                Option::None => clusters.push((key, vec![middle])),
+            }
+        }
+
+        // Nothing clustered together beyond singletons, so there's no further structure to
+        // recurse into -- keep the original flat representation.
+        if clusters.iter().all(|(_, group)| group.len() == 1) {
+            return Option::Some(MatcherToken::OneOfText(middles.to_vec()));
+        }
+
+        let sequences = clusters
+            .into_iter()
+            .map(|(_, group)| Self::compile(&group))
+            .collect();
+        Option::Some(MatcherToken::OneOfSequences(sequences))
+    }
+
+    /// The length, in bytes, of the longest prefix shared by every word, rounded down to a
+    /// `char` boundary.
+    fn common_prefix_len(words: &[&str]) -> usize {
+        let first = words[0];
+        let mut len = words
+            .iter()
+            .map(|word| {
+                first
+                    .as_bytes()
+                    .iter()
+                    .zip(word.as_bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            })
+            .min()
+            .unwrap_or(0);
+        while len > 0 && !first.is_char_boundary(len) {
+            len -= 1;
+        }
+        len
+    }
+
+    /// The length, in bytes, of the longest suffix shared by every word, not overlapping the
+    /// already-factored-out prefix, and rounded down to a `char` boundary.
+    fn common_suffix_len(words: &[&str], prefix_len: usize) -> usize {
+        let first = words[0];
+        let min_remaining = words
+            .iter()
+            .map(|word| word.len() - prefix_len)
+            .min()
+            .unwrap_or(0);
+
+        let mut len = 0;
+        while len < min_remaining {
+            let candidate = first.as_bytes()[first.len() - len - 1];
+            let all_match = words
+                .iter()
+                .all(|word| word.as_bytes()[word.len() - len - 1] == candidate);
+            if !all_match {
+                break;
+            }
+            len += 1;
+        }
+        while len > 0 && !first.is_char_boundary(first.len() - len) {
+            len -= 1;
+        }
+        len
+    }
 }
 
 fn main() {
@@ -179,7 +642,37 @@ fn main() {
 
 #[cfg(test)]
 mod test {
-    use super::{Matcher, MatcherToken};
+    use super::{Matcher, MatcherBuilder, MatcherPatternParseError, MatcherToken, Position};
+
+    #[test]
+    fn fuzzy_matching_tolerates_typos_within_budget() {
+        let match_string = "hello(cat|dog)".to_string();
+        let mut matcher = Matcher::new(&match_string).unwrap().with_max_edits(1);
+
+        // "helo" is "hello" missing an 'l' (one deletion); "kat" is "cat" with one
+        // substitution -- both are within the one-edit budget.
+        let candidate = "helokat".to_string();
+        let result = matcher.match_string(&candidate);
+        assert_eq!(
+            result,
+            vec![
+                (&MatcherToken::RawText("hello"), "helo"),
+                (&MatcherToken::OneOfText(vec!["cat", "dog"]), "kat"),
+            ]
+        );
+    }
+
+    #[test]
+    fn fuzzy_matching_rejects_too_many_edits() {
+        let match_string = "hello".to_string();
+        let mut matcher = Matcher::new(&match_string).unwrap().with_max_edits(1);
+
+        // "hxlo" is two edits away from "hello" (substitute 'e', delete an 'l') -- beyond
+        // the one-edit budget, so it shouldn't match at all.
+        let candidate = "hxlo".to_string();
+        assert_eq!(matcher.match_string(&candidate), vec![]);
+    }
+
     #[test]
     fn simple_test() {
         let match_string = "abc(d|e|f).".to_string();
@@ -210,10 +703,228 @@ mod test {
         }
     }
 
+    #[test]
+    fn find_prefers_more_tokens_matched_over_earlier_offset() {
+        let match_string = "a.b".to_string();
+        let mut matcher = Matcher::new(&match_string).unwrap();
+
+        // At offset 0, "axx" only matches the first two tokens ('a', then the wildcard) --
+        // the next char isn't 'b'. A full three-token match only shows up later, at "azb",
+        // so `find` should skip the earlier, worse candidate in favor of it.
+        let haystack = "axx azb".to_string();
+        let result = matcher.find(&haystack);
+        assert_eq!(
+            result,
+            Some((
+                4,
+                vec![
+                    (&MatcherToken::RawText("a"), "a"),
+                    (&MatcherToken::WildCard, "z"),
+                    (&MatcherToken::RawText("b"), "b"),
+                ]
+            ))
+        );
+        assert_eq!(matcher.most_tokens_matched, 3);
+    }
+
     #[test]
     fn broken_matcher() {
         let match_string = "abc(d|e|f.".to_string();
         let matcher = Matcher::new(&match_string);
-        assert_eq!(matcher, None);
+        // The trailing `.` is still inside the unclosed `(...)` group, so it's rejected as a
+        // wildcard-in-`OneOf`, pointing at the `.` itself.
+        assert_eq!(
+            matcher,
+            Err(MatcherPatternParseError::WildcardInOneOf(Position { byte: 9, line: 1, col: 10 }))
+        );
+    }
+
+    #[test]
+    fn unterminated_group_reports_opening_paren() {
+        let match_string = "abc(d|e|f".to_string();
+        let matcher = Matcher::new(&match_string);
+        assert_eq!(
+            matcher,
+            Err(MatcherPatternParseError::Incomplete(Position { byte: 3, line: 1, col: 4 }))
+        );
+    }
+
+    #[test]
+    fn quantifiers() {
+        let match_string = "ab*(c|d)+e?".to_string();
+        let mut matcher = Matcher::new(&match_string).unwrap();
+
+        let candidate1 = "abbbcde".to_string();
+        let result = matcher.match_string(&candidate1);
+        assert_eq!(
+            result,
+            vec![
+                (&MatcherToken::RawText("a"), "a"),
+                (&MatcherToken::Repeat {
+                    inner: Box::new(MatcherToken::RawText("b")),
+                    min: 0,
+                    max: Option::None,
+                }, "bbb"),
+                (&MatcherToken::Repeat {
+                    inner: Box::new(MatcherToken::OneOfText(vec!["c", "d"])),
+                    min: 1,
+                    max: Option::None,
+                }, "cd"),
+                (&MatcherToken::Repeat {
+                    inner: Box::new(MatcherToken::RawText("e")),
+                    min: 0,
+                    max: Option::Some(1),
+                }, "e"),
+            ]
+        );
+
+        // `b*` and `e?` are both happy to match zero repetitions.
+        let candidate2 = "acd".to_string();
+        let result = matcher.match_string(&candidate2);
+        assert_eq!(
+            result,
+            vec![
+                (&MatcherToken::RawText("a"), "a"),
+                (&MatcherToken::Repeat {
+                    inner: Box::new(MatcherToken::RawText("b")),
+                    min: 0,
+                    max: Option::None,
+                }, ""),
+                (&MatcherToken::Repeat {
+                    inner: Box::new(MatcherToken::OneOfText(vec!["c", "d"])),
+                    min: 1,
+                    max: Option::None,
+                }, "cd"),
+                (&MatcherToken::Repeat {
+                    inner: Box::new(MatcherToken::RawText("e")),
+                    min: 0,
+                    max: Option::Some(1),
+                }, ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn quantifier_with_nothing_to_repeat() {
+        let match_string = "*abc".to_string();
+        let matcher = Matcher::new(&match_string);
+        assert_eq!(
+            matcher,
+            Err(MatcherPatternParseError::QuantifierWithNothingToRepeat(Position {
+                byte: 0,
+                line: 1,
+                col: 1,
+            }))
+        );
+    }
+
+    #[test]
+    fn stacked_quantifiers_are_rejected() {
+        let match_string = "a**".to_string();
+        let matcher = Matcher::new(&match_string);
+        assert_eq!(
+            matcher,
+            Err(MatcherPatternParseError::StackedQuantifier(Position { byte: 2, line: 1, col: 3 }))
+        );
+    }
+
+    #[test]
+    fn quantifier_inside_group_is_rejected() {
+        let match_string = "(a*)".to_string();
+        let matcher = Matcher::new(&match_string);
+        assert_eq!(
+            matcher,
+            Err(MatcherPatternParseError::QuantifierNotAllowedInOneOf(Position {
+                byte: 2,
+                line: 1,
+                col: 3,
+            }))
+        );
+    }
+
+    #[test]
+    fn builder_factors_shared_suffix() {
+        // All four share the trailing "ber", but no common leading prefix.
+        let months = ["September", "October", "November", "December"];
+        let mut matcher = MatcherBuilder::from_words(&months);
+        assert_eq!(
+            matcher.tokens,
+            vec![
+                MatcherToken::OneOfText(vec!["Septem", "Octo", "Novem", "Decem"]),
+                MatcherToken::RawText("ber"),
+            ],
+        );
+
+        for month in months {
+            assert_eq!(matcher.match_string(month).last().unwrap().1, "ber");
+            let matched_len: usize = matcher.match_string(month).iter().map(|(_, m)| m.len()).sum();
+            assert_eq!(matched_len, month.len());
+        }
+    }
+
+    #[test]
+    fn builder_factors_shared_prefix_with_differing_middle() {
+        let words = ["catastrophe", "catnap", "category"];
+        let matcher = MatcherBuilder::from_words(&words);
+        assert_eq!(
+            matcher.tokens,
+            vec![
+                MatcherToken::RawText("cat"),
+                MatcherToken::OneOfText(vec!["astrophe", "nap", "egory"]),
+            ],
+        );
+    }
+
+    #[test]
+    fn builder_handles_a_word_that_is_exactly_the_prefix_plus_suffix() {
+        // "catg" has nothing between the shared "cat" prefix and "g" suffix, so its
+        // `OneOfText` alternative is the empty string -- it must still match in full.
+        let words = ["catalog", "catdog", "catg"];
+        let mut matcher = MatcherBuilder::from_words(&words);
+        assert_eq!(
+            matcher.tokens,
+            vec![
+                MatcherToken::RawText("cat"),
+                MatcherToken::OneOfText(vec!["alo", "do", ""]),
+                MatcherToken::RawText("g"),
+            ],
+        );
+
+        for word in words {
+            let result = matcher.match_string(word);
+            let matched_len: usize = result.iter().map(|(_, m)| m.len()).sum();
+            assert_eq!(matched_len, word.len(), "failed to fully match {word:?}");
+        }
+    }
+
+    #[test]
+    fn builder_recurses_into_clusters_that_share_further_structure() {
+        // "dog" breaks "cat" as a prefix shared by *every* word, but the other three still
+        // share it among themselves, and "aclysm"/"apult" share a further "a" beyond that.
+        let words = ["cataclysm", "catapult", "category", "dog"];
+        let matcher = MatcherBuilder::from_words(&words);
+        assert_eq!(
+            matcher.tokens,
+            vec![MatcherToken::OneOfSequences(vec![
+                vec![
+                    MatcherToken::RawText("cat"),
+                    MatcherToken::OneOfSequences(vec![
+                        vec![
+                            MatcherToken::RawText("a"),
+                            MatcherToken::OneOfText(vec!["clysm", "pult"]),
+                        ],
+                        vec![MatcherToken::RawText("egory")],
+                    ]),
+                ],
+                vec![MatcherToken::RawText("dog")],
+            ])],
+        );
+
+        for word in words {
+            let mut matcher = MatcherBuilder::from_words(&words);
+            let result = matcher.match_string(word);
+            let matched_len: usize = result.iter().map(|(_, m)| m.len()).sum();
+            assert_eq!(matched_len, word.len(), "failed to fully match {word:?}");
+        }
     }
 }